@@ -0,0 +1,308 @@
+use std::collections::HashMap;
+
+use biome_analyze::context::RuleContext;
+use biome_analyze::{declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyTsName, AnyTsType, JsSyntaxKind, JsSyntaxNode, TsReferenceType, TsTypeParameter,
+    TsTypeParameters, T,
+};
+use biome_rowan::{AstNode, AstSeparatedList, BatchMutationExt, WalkEvent};
+
+use crate::utils::type_parameter_name;
+use crate::JsRuleAction;
+
+declare_rule! {
+    /// Disallow type parameters that are only used once.
+    ///
+    /// A generic type parameter that appears at most once in the signature it belongs
+    /// to doesn't relate anything to anything else, so it can always be replaced by its
+    /// constraint (or `unknown` when it has none) without changing the meaning of the
+    /// declaration. Such a parameter is a sign that the generic was introduced without
+    /// actually needing genericity.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts,expect_diagnostic
+    /// function f<T>(x: T): void {}
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// function g<T extends string>(x: T): void {}
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// function f<T>(x: T): T {
+    ///     return x;
+    /// }
+    /// ```
+    ///
+    /// ```ts
+    /// function g<T, U extends T>(x: T, y: U): U {
+    ///     return y;
+    /// }
+    /// ```
+    pub(crate) NoUnnecessaryTypeParameters {
+        version: "next",
+        name: "noUnnecessaryTypeParameters",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct UnnecessaryTypeParameter {
+    parameter: TsTypeParameter,
+    /// The `<...>` list `parameter` is declared in, needed to clean up the surrounding
+    /// commas/angle brackets when the parameter is removed.
+    type_parameters: TsTypeParameters,
+    /// The single usage of the parameter outside of its own declaration, if any.
+    /// `None` means the parameter isn't referenced outside its own `extends`/default
+    /// clause and any nested scope that shadows it.
+    sole_usage: Option<TsReferenceType>,
+}
+
+impl Rule for NoUnnecessaryTypeParameters {
+    type Query = Ast<TsTypeParameters>;
+    type State = UnnecessaryTypeParameter;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let Some(owner) = node.syntax().parent() else {
+            return Vec::new();
+        };
+
+        let declared: Vec<(String, TsTypeParameter)> = node
+            .items()
+            .iter()
+            .filter_map(|parameter| parameter.ok())
+            .filter_map(|parameter| Some((type_parameter_name(&parameter)?, parameter)))
+            .collect();
+        if declared.is_empty() {
+            return Vec::new();
+        }
+
+        // Scopes nested under `owner` that redeclare (shadow) one of our names, keyed by
+        // the scope-owning node itself (not the `<...>` list): the list is a small child
+        // positioned before the parameters/return type/body it's meant to shadow, so using
+        // it as the shadow's extent would miss everything after it.
+        let mut shadowing_scopes: HashMap<JsSyntaxNode, Vec<&str>> = HashMap::new();
+        for descendant in owner.descendants() {
+            let Some(nested) = TsTypeParameters::cast_ref(&descendant) else {
+                continue;
+            };
+            if nested.syntax() == node.syntax() {
+                continue;
+            }
+            let Some(nested_owner) = nested.syntax().parent() else {
+                continue;
+            };
+            let shadowed: Vec<&str> = declared
+                .iter()
+                .map(|(name, _)| name.as_str())
+                .filter(|name| nested_declares(&nested, name))
+                .collect();
+            if !shadowed.is_empty() {
+                shadowing_scopes.entry(nested_owner).or_default().extend(shadowed);
+            }
+        }
+
+        let mut shadow_depth: HashMap<&str, u32> = HashMap::new();
+        let mut usages: HashMap<String, Vec<TsReferenceType>> = HashMap::new();
+
+        for event in owner.preorder() {
+            match event {
+                WalkEvent::Enter(descendant) => {
+                    if let Some(names) = shadowing_scopes.get(&descendant) {
+                        for name in names {
+                            *shadow_depth.entry(name).or_default() += 1;
+                        }
+                    }
+                    if let Some(reference) = TsReferenceType::cast_ref(&descendant) {
+                        if let Some(name) = reference_identifier_name(&reference) {
+                            if let Some((declared_name, _)) =
+                                declared.iter().find(|(candidate, _)| *candidate == name)
+                            {
+                                if shadow_depth.get(declared_name.as_str()).copied().unwrap_or(0) == 0
+                                    && !is_within_own_clause(&declared, declared_name, &reference)
+                                {
+                                    usages.entry(declared_name.clone()).or_default().push(reference);
+                                }
+                            }
+                        }
+                    }
+                }
+                WalkEvent::Leave(descendant) => {
+                    if let Some(names) = shadowing_scopes.get(&descendant) {
+                        for name in names {
+                            if let Some(depth) = shadow_depth.get_mut(name) {
+                                *depth = depth.saturating_sub(1);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        declared
+            .into_iter()
+            .filter_map(|(name, parameter)| {
+                if collides_with_enclosing_scope(&owner, &name) {
+                    return None;
+                }
+                let mut found = usages.remove(&name).unwrap_or_default();
+                if found.len() > 1 {
+                    return None;
+                }
+                if let Some(usage) = found.first() {
+                    if binds_twice(usage) {
+                        return None;
+                    }
+                }
+                Some(UnnecessaryTypeParameter {
+                    parameter,
+                    type_parameters: node.clone(),
+                    sole_usage: found.pop(),
+                })
+            })
+            .collect()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = type_parameter_name(&state.parameter)?;
+        let usage_phrase = if state.sole_usage.is_some() {
+            "is used only once and doesn't relate other signature pieces together"
+        } else {
+            "is never used"
+        };
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.parameter.syntax().text_trimmed_range(),
+                markup! {
+                    "The type parameter "<Emphasis>{name}</Emphasis>" "{usage_phrase}"."
+                },
+            )
+            .note(markup! {
+                "A type parameter that appears at most once can always be replaced by its constraint, or "<Emphasis>"unknown"</Emphasis>" if it has none."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let parameter = &state.parameter;
+        let type_parameters = &state.type_parameters;
+
+        let mut mutation = ctx.root().begin();
+        if let Some(usage) = &state.sole_usage {
+            mutation.replace_node(
+                AnyTsType::TsReferenceType(usage.clone()),
+                replacement_type(parameter),
+            );
+        }
+
+        let remaining: Vec<TsTypeParameter> = type_parameters
+            .items()
+            .iter()
+            .filter_map(|item| item.ok())
+            .filter(|item| item.syntax() != parameter.syntax())
+            .collect();
+        if remaining.is_empty() {
+            mutation.remove_node(type_parameters.clone());
+        } else {
+            let separator_count = remaining.len() - 1;
+            let separators = std::iter::repeat_with(|| make::token(T![,])).take(separator_count);
+            mutation.replace_node(
+                type_parameters.items(),
+                make::ts_type_parameter_list(remaining, separators),
+            );
+        }
+
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Remove the unnecessary type parameter." }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+fn nested_declares(nested: &TsTypeParameters, name: &str) -> bool {
+    nested
+        .items()
+        .iter()
+        .filter_map(|parameter| parameter.ok())
+        .any(|parameter| type_parameter_name(&parameter).as_deref() == Some(name))
+}
+
+/// Whether some ancestor scope of `owner` also declares a type parameter named `name`.
+/// Rewriting a parameter that merely shadows an outer type parameter of the same name
+/// would silently change what the remaining references bind to, so we leave those
+/// alone. Only type parameter lists are inspected: type aliases, interfaces, classes
+/// or imports of the same name in an enclosing scope are not detected.
+fn collides_with_enclosing_scope(owner: &JsSyntaxNode, name: &str) -> bool {
+    owner.ancestors().skip(1).any(|ancestor| {
+        ancestor
+            .children()
+            .find_map(TsTypeParameters::cast)
+            .is_some_and(|type_parameters| nested_declares(&type_parameters, name))
+    })
+}
+
+/// Whether `usage` sits inside a mapped or conditional type, where a single textual
+/// occurrence can still bind two positions (e.g. a mapped type's key clause also
+/// drives its implicit index signature, and `infer` introduces a second binding
+/// alongside the checked type). Such usages aren't safe to treat as "used once".
+fn binds_twice(usage: &TsReferenceType) -> bool {
+    usage.syntax().ancestors().skip(1).any(|ancestor| {
+        matches!(
+            ancestor.kind(),
+            JsSyntaxKind::TS_MAPPED_TYPE | JsSyntaxKind::TS_CONDITIONAL_TYPE
+        )
+    })
+}
+
+fn reference_identifier_name(reference: &TsReferenceType) -> Option<String> {
+    match reference.name().ok()? {
+        AnyTsName::TsIdentifierReference(identifier) => {
+            Some(identifier.value_token().ok()?.text_trimmed().to_string())
+        }
+        AnyTsName::TsQualifiedName(_) => None,
+    }
+}
+
+/// A parameter's appearance inside its own `extends`/default clause (e.g. the `T`
+/// in `T extends Foo<T>`) doesn't count as a genuine usage: it doesn't relate the
+/// parameter to anything outside its own declaration.
+fn is_within_own_clause(
+    declared: &[(String, TsTypeParameter)],
+    name: &str,
+    reference: &TsReferenceType,
+) -> bool {
+    let Some((_, parameter)) = declared.iter().find(|(candidate, _)| candidate == name) else {
+        return false;
+    };
+    let range = reference.syntax().text_trimmed_range();
+    let in_constraint = parameter
+        .constraint()
+        .is_some_and(|clause| clause.syntax().text_trimmed_range().contains_range(range));
+    let in_default = parameter
+        .default()
+        .is_some_and(|clause| clause.syntax().text_trimmed_range().contains_range(range));
+    in_constraint || in_default
+}
+
+fn replacement_type(parameter: &TsTypeParameter) -> AnyTsType {
+    parameter
+        .constraint()
+        .and_then(|clause| clause.ty().ok())
+        .unwrap_or_else(|| AnyTsType::TsUnknownType(make::ts_unknown_type(make::token(T![unknown]))))
+}
@@ -3,9 +3,10 @@ use biome_analyze::{declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagno
 use biome_console::markup;
 
 use biome_diagnostics::Applicability;
-use biome_js_syntax::{AnyTsType, TsTypeConstraintClause};
+use biome_js_syntax::TsTypeConstraintClause;
 use biome_rowan::{AstNode, BatchMutationExt};
 
+use crate::utils::{resolve_top_type, TopKind};
 use crate::JsRuleAction;
 
 declare_rule! {
@@ -16,6 +17,9 @@ declare_rule! {
     /// All types are subtypes of `any` and `unknown`.
     /// It is thus useless to extend from `any` or `unknown`.
     ///
+    /// This also applies when the constraint merely *reduces* to `any` or `unknown`, such as
+    /// `T extends string | unknown` (always `unknown`) or `T extends (unknown)`.
+    ///
     /// Source: https://typescript-eslint.io/rules/no-unnecessary-type-constraint/
     ///
     /// ## Examples
@@ -72,6 +76,12 @@ declare_rule! {
     ///
     /// type Bar<T> = {};
     ///```
+    ///
+    /// ## Notes
+    ///
+    /// See also [useNarrowedTypeConstraint], an opt-in companion rule that, instead of
+    /// removing the constraint, suggests narrowing it to whatever the type parameter's
+    /// usage implies.
     pub(crate) NoUselessTypeConstraint {
         version: "1.0.0",
         name: "noUselessTypeConstraint",
@@ -82,28 +92,29 @@ declare_rule! {
 
 impl Rule for NoUselessTypeConstraint {
     type Query = Ast<TsTypeConstraintClause>;
-    type State = ();
+    type State = TopKind;
     type Signals = Option<Self::State>;
     type Options = ();
 
     fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
         let node = ctx.query();
         let ty = node.ty().ok()?;
-        matches!(ty, AnyTsType::TsAnyType(_) | AnyTsType::TsUnknownType(_)).then_some(())
+        resolve_top_type(&ty)
     }
 
-    fn diagnostic(ctx: &RuleContext<Self>, _state: &Self::State) -> Option<RuleDiagnostic> {
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
         let node = ctx.query();
+        let top_kind = state.as_str();
         Some(
             RuleDiagnostic::new(
                 rule_category!(),
                 node.syntax().text_trimmed_range(),
                 markup! {
-                    "Constraining a type parameter to "<Emphasis>"any"</Emphasis>" or "<Emphasis>"unknown"</Emphasis>" is useless."
+                    "Constraining a type parameter to "<Emphasis>{top_kind}</Emphasis>" is useless."
                 },
             )
             .note(markup! {
-                "All types are subtypes of "<Emphasis>"any"</Emphasis>" and "<Emphasis>"unknown"</Emphasis>"."
+                "All types are subtypes of "<Emphasis>{top_kind}</Emphasis>"."
             }),
         )
     }
@@ -0,0 +1,262 @@
+use biome_analyze::context::RuleContext;
+use biome_analyze::{declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic};
+use biome_console::markup;
+
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyJsBindingPattern, AnyJsExpression, AnyTsName, AnyTsReturnType, AnyTsType,
+    JsCallExpression, JsComputedMemberExpression, JsFormalParameter, JsIdentifierExpression,
+    JsStaticMemberExpression, JsSyntaxNode, TsTypeConstraintClause, TsTypeParameter, T,
+};
+use biome_rowan::AstNode;
+use biome_rowan::BatchMutationExt;
+
+use crate::utils::{resolve_top_type, type_parameter_name};
+use crate::JsRuleAction;
+
+declare_rule! {
+    /// Suggest a narrower type constraint based on how the type parameter is used.
+    ///
+    /// When a type parameter is constrained to `any` or `unknown`
+    /// ([noUselessTypeConstraint](https://biomejs.dev/linter/rules/no-useless-type-constraint/)
+    /// already flags and safely removes such a constraint), the way its values are used
+    /// in the signature's body often implies a tighter bound: accessing a member implies
+    /// `extends object`, calling it implies a callable signature, and indexing it implies
+    /// `extends unknown[]`.
+    ///
+    /// This rule is a suggestion, not a correction: Biome has no type checker, so the
+    /// inferred constraint is only as good as the syntactic usages it can see, and may be
+    /// narrower or wider than what the author actually intended. Prefer
+    /// `noUselessTypeConstraint`'s safe removal fix when in doubt; use this rule's fix when
+    /// you do want the constraint tightened instead of dropped.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts,expect_diagnostic
+    /// function f<T extends any>(value: T) {
+    ///     return value.length;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// function f<T extends object>(value: T) {
+    ///     return value.length;
+    /// }
+    /// ```
+    pub(crate) UseNarrowedTypeConstraint {
+        version: "next",
+        name: "useNarrowedTypeConstraint",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+impl Rule for UseNarrowedTypeConstraint {
+    type Query = Ast<TsTypeConstraintClause>;
+    type State = Capability;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        let node = ctx.query();
+        let ty = node.ty().ok()?;
+        resolve_top_type(&ty)?;
+
+        let parameter = TsTypeParameter::cast(node.syntax().parent()?)?;
+        let name = type_parameter_name(&parameter)?;
+        // `node` (the constraint clause) -> `parameter` -> the parameter list ->
+        // `TsTypeParameters` -> the function/method/class/etc. that owns the generic's
+        // signature and body.
+        let owner = parameter.syntax().parent()?.parent()?.parent()?;
+        Capability::infer(&owner, &name)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _state: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        Some(RuleDiagnostic::new(
+            rule_category!(),
+            node.syntax().text_trimmed_range(),
+            markup! {
+                "This constraint could be narrowed based on how the type parameter is used."
+            },
+        ))
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let node = ctx.query();
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(node.ty().ok()?, state.clone().into_type());
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Restrict the constraint based on how the type parameter is used." }
+                .to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// The capabilities a type parameter's usage implies it needs, gathered by looking at
+/// how values whose declared type is the parameter are used as expressions in the
+/// enclosing signature.
+///
+/// Biome doesn't have a type checker, so "an expression whose static type is `T`" is
+/// approximated syntactically: a formal parameter annotated directly with `T` (modulo
+/// parentheses). That covers the common `function f<T>(x: T)` shape this rule targets;
+/// anything inferred through assignment or destructuring is out of scope.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Capability {
+    /// Accessed as `value.foo`.
+    object: bool,
+    /// Called as `value()`.
+    function: bool,
+    /// Indexed as `value[0]`.
+    index: bool,
+}
+
+impl Capability {
+    fn infer(owner: &JsSyntaxNode, name: &str) -> Option<Self> {
+        let value_names = typed_value_bindings(owner, name);
+        if value_names.is_empty() {
+            return None;
+        }
+
+        let mut capability = Self::default();
+        for descendant in owner.descendants() {
+            let Some(identifier) = JsIdentifierExpression::cast(descendant) else {
+                continue;
+            };
+            let Ok(reference) = identifier.name() else {
+                continue;
+            };
+            let Ok(token) = reference.value_token() else {
+                continue;
+            };
+            if !value_names.iter().any(|value_name| value_name == token.text_trimmed()) {
+                continue;
+            }
+            let Some(parent) = identifier.syntax().parent() else {
+                continue;
+            };
+            let is_self = |expression: Option<AnyJsExpression>| {
+                expression.is_some_and(|expression| expression.syntax() == identifier.syntax())
+            };
+            if let Some(member) = JsStaticMemberExpression::cast_ref(&parent) {
+                if is_self(member.object().ok()) {
+                    capability.object = true;
+                }
+            } else if let Some(call) = JsCallExpression::cast_ref(&parent) {
+                if is_self(call.callee().ok()) {
+                    capability.function = true;
+                }
+            } else if let Some(member) = JsComputedMemberExpression::cast_ref(&parent) {
+                if is_self(member.object().ok()) {
+                    capability.index = true;
+                }
+            }
+        }
+        (capability.object || capability.function || capability.index).then_some(capability)
+    }
+
+    fn into_type(self) -> AnyTsType {
+        let mut members = Vec::new();
+        if self.object {
+            members.push(AnyTsType::TsNonPrimitiveType(make::ts_non_primitive_type(
+                make::token(T![object]),
+            )));
+        }
+        if self.function {
+            members.push(make_callable_type());
+        }
+        if self.index {
+            members.push(AnyTsType::TsArrayType(make::ts_array_type(
+                AnyTsType::TsUnknownType(make::ts_unknown_type(make::token(T![unknown]))),
+                make::token(T!['[']),
+                make::token(T![']']),
+            )));
+        }
+        let mut members = members.into_iter();
+        let first = members.next().expect("at least one capability inferred");
+        members.fold(first, |left, right| {
+            AnyTsType::TsIntersectionType(
+                make::ts_intersection_type(make::ts_intersection_type_element_list(
+                    [left, right],
+                    [make::token(T![&])],
+                ))
+                .build(),
+            )
+        })
+    }
+}
+
+/// Names of formal parameters directly annotated with the type parameter `type_name`,
+/// e.g. `x` in `function f<T>(x: T)`.
+fn typed_value_bindings(owner: &JsSyntaxNode, type_name: &str) -> Vec<String> {
+    owner
+        .descendants()
+        .filter_map(JsFormalParameter::cast)
+        .filter_map(|parameter| {
+            let annotation = parameter.type_annotation()?;
+            let ty = annotation.ty().ok()?;
+            if reference_type_name(&ty).as_deref() != Some(type_name) {
+                return None;
+            }
+            match parameter.binding().ok()? {
+                AnyJsBindingPattern::AnyJsBinding(binding) => {
+                    Some(binding.as_js_identifier_binding()?.name_token().ok()?.text_trimmed().to_string())
+                }
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// The name a (possibly parenthesized) type resolves to when it's a simple,
+/// non-generic reference such as `T` — not `T<U>` or `Namespace.T`.
+fn reference_type_name(ty: &AnyTsType) -> Option<String> {
+    match ty {
+        AnyTsType::TsParenthesizedType(parenthesized) => {
+            reference_type_name(&parenthesized.ty().ok()?)
+        }
+        AnyTsType::TsReferenceType(reference) => {
+            if reference.type_arguments().is_some() {
+                return None;
+            }
+            match reference.name().ok()? {
+                AnyTsName::TsIdentifierReference(identifier) => {
+                    Some(identifier.value_token().ok()?.text_trimmed().to_string())
+                }
+                AnyTsName::TsQualifiedName(_) => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Builds `(...args: any[]) => any`, the constraint implied by calling a value.
+fn make_callable_type() -> AnyTsType {
+    let rest_name = make::js_identifier_binding(make::ident("args"));
+    let rest_type = AnyTsType::TsArrayType(make::ts_array_type(
+        AnyTsType::TsAnyType(make::ts_any_type(make::token(T![any]))),
+        make::token(T!['[']),
+        make::token(T![']']),
+    ));
+    let rest_parameter = make::ts_rest_parameter(make::token(T![...]), rest_name.into())
+        .with_type_annotation(make::ts_type_annotation(make::token(T![:]), rest_type))
+        .build();
+    let parameters = make::js_parameters(
+        make::token(T!['(']),
+        make::js_parameter_list([rest_parameter.into()], []),
+        make::token(T![')']),
+    );
+    let return_type =
+        AnyTsReturnType::AnyTsType(AnyTsType::TsAnyType(make::ts_any_type(make::token(T![any]))));
+    AnyTsType::TsFunctionType(
+        make::ts_function_type(parameters, make::token(T![=>]), return_type).build(),
+    )
+}
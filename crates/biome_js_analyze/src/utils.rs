@@ -0,0 +1,95 @@
+use biome_js_syntax::{
+    AnyTsType, TsIntersectionType, TsParenthesizedType, TsTypeParameter, TsUnionType,
+};
+use biome_rowan::AstNode;
+
+/// The "top type" a [AnyTsType] is equivalent to, if any.
+///
+/// TypeScript has two top types, `any` and `unknown`. Every other type is a
+/// subtype of both. This enum distinguishes them so that the rules that
+/// care about useless top-type usage can name the one a type collapses to
+/// in their diagnostics.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum TopKind {
+    Any,
+    Unknown,
+}
+
+impl TopKind {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            TopKind::Any => "any",
+            TopKind::Unknown => "unknown",
+        }
+    }
+}
+
+/// Returns the [TopKind] that `ty` is structurally equivalent to, or `None`
+/// if `ty` isn't equivalent to a top type.
+///
+/// This recurses through the constructs that preserve or collapse to a top
+/// type under the usual subtyping lattice:
+///
+/// - `(T)` is equivalent to `T`.
+/// - In a union `A | B`, a member that is `any` makes the whole union `any`
+///   (`any` absorbs everything); otherwise a member that is `unknown` makes
+///   the whole union `unknown`, since `X | unknown` is always `unknown`.
+/// - In an intersection `A & B`, a member that is `any` makes the whole
+///   intersection `any`; otherwise the intersection is only a top type when
+///   *every* member is, since `X & unknown` is just `X`.
+/// - A leaf `any`/`unknown` type maps to its own kind.
+/// - Anything else is not a top type.
+pub(crate) fn resolve_top_type(ty: &AnyTsType) -> Option<TopKind> {
+    match ty {
+        AnyTsType::TsAnyType(_) => Some(TopKind::Any),
+        AnyTsType::TsUnknownType(_) => Some(TopKind::Unknown),
+        AnyTsType::TsParenthesizedType(parenthesized) => resolve_parenthesized_top_type(parenthesized),
+        AnyTsType::TsUnionType(union_type) => resolve_union_top_type(union_type),
+        AnyTsType::TsIntersectionType(intersection_type) => {
+            resolve_intersection_top_type(intersection_type)
+        }
+        _ => None,
+    }
+}
+
+fn resolve_parenthesized_top_type(parenthesized: &TsParenthesizedType) -> Option<TopKind> {
+    let inner = parenthesized.ty().ok()?;
+    resolve_top_type(&inner)
+}
+
+fn resolve_union_top_type(union_type: &TsUnionType) -> Option<TopKind> {
+    let mut result = None;
+    for member in union_type.types().iter().flatten() {
+        match resolve_top_type(&member) {
+            Some(TopKind::Any) => return Some(TopKind::Any),
+            Some(TopKind::Unknown) => result = Some(TopKind::Unknown),
+            None => {}
+        }
+    }
+    result
+}
+
+fn resolve_intersection_top_type(intersection_type: &TsIntersectionType) -> Option<TopKind> {
+    let mut result = Some(TopKind::Unknown);
+    for member in intersection_type.types().iter().flatten() {
+        match resolve_top_type(&member) {
+            Some(TopKind::Any) => return Some(TopKind::Any),
+            Some(TopKind::Unknown) => {}
+            None => result = None,
+        }
+    }
+    result
+}
+
+/// Returns the declared name of a type parameter, e.g. `T` in `T extends unknown`.
+pub(crate) fn type_parameter_name(parameter: &TsTypeParameter) -> Option<String> {
+    Some(
+        parameter
+            .name()
+            .ok()?
+            .ident_token()
+            .ok()?
+            .text_trimmed()
+            .to_string(),
+    )
+}